@@ -0,0 +1,35 @@
+use kaspa_consensus_core::tx::TransactionId;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Crate-wide error type for `kaspa-wallet-core`.
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("{0}")]
+    Custom(String),
+
+    #[error(transparent)]
+    Rpc(#[from] kaspa_rpc_core::RpcError),
+
+    #[error("transaction {id} did not propagate within {elapsed:?}")]
+    ConfirmationTimeout { id: TransactionId, elapsed: Duration },
+
+    #[error("script verification failed for input {input_index}: {source}")]
+    ScriptVerification {
+        input_index: usize,
+        #[source]
+        source: kaspa_txscript::TxScriptError,
+    },
+}
+
+impl<T> From<std::sync::PoisonError<T>> for Error {
+    fn from(_: std::sync::PoisonError<T>) -> Self {
+        Error::Custom("mutex poisoned".to_string())
+    }
+}
+
+impl From<String> for Error {
+    fn from(msg: String) -> Self {
+        Error::Custom(msg)
+    }
+}
@@ -1,18 +1,79 @@
+use crate::error::Error;
 use crate::result::Result;
 use crate::tx::Generator;
 use crate::utxo::UtxoEntryReference;
 use crate::DynRpcApi;
+use futures::stream::{self, StreamExt};
 use kaspa_addresses::Address;
 use kaspa_consensus_core::sign::sign_with_multiple_v2;
 use kaspa_consensus_core::tx::{SignableTransaction, Transaction, TransactionId};
 use kaspa_rpc_core::{RpcTransaction, RpcTransactionId};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Mutex;
 use std::sync::{
-    atomic::{AtomicBool, Ordering},
+    atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
     Arc,
 };
+use std::time::Duration;
 use workflow_log::log_info;
 
+/// Interval at which [`PendingTransaction::confirmations`] polls the node for
+/// mempool presence and DAA score progression.
+const CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A single co-signer's candidate signature script for one input, collected as part of a
+/// [`PartialSignatures`] hand-off bundle. Kaspa has no redeem-script/signature-combination
+/// primitive to assemble several co-signers' signatures into one combined script, so this
+/// only supports single-signer hand-off per input (exactly one candidate per input), not
+/// true m-of-n multisig aggregation - see [`PendingTransaction::finalize`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartialSignature {
+    pub input_index: usize,
+    pub pubkey: [u8; 32],
+    pub signature_script: Vec<u8>,
+}
+
+/// A serializable bundle of partial signatures produced by
+/// [`PendingTransaction::try_sign_partial`], meant to be exchanged between co-signers
+/// and accumulated via [`PendingTransaction::merge_signatures`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartialSignatures {
+    pub transaction_id: TransactionId,
+    pub signatures: Vec<PartialSignature>,
+}
+
+/// Options controlling [`PendingTransaction::try_submit_and_await`].
+#[derive(Debug, Clone, Copy)]
+pub struct SubmitOptions {
+    /// Wall-clock deadline after which an unpropagated transaction is given up on.
+    pub timeout: Duration,
+    /// Interval between rebroadcast attempts while waiting for propagation.
+    pub rebroadcast_interval: Duration,
+}
+
+impl Default for SubmitOptions {
+    fn default() -> Self {
+        Self { timeout: Duration::from_secs(60), rebroadcast_interval: Duration::from_secs(5) }
+    }
+}
+
+/// Progression of a [`PendingTransaction`] from submission to confirmation, as
+/// tracked by [`PendingTransaction::confirmations`] and observable via
+/// [`PendingTransaction::status`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfirmationState {
+    /// The transaction has been submitted but its mempool status is not yet known.
+    Submitted,
+    /// The transaction is sitting in the node's mempool, not yet accepted into a block.
+    Mempool,
+    /// The transaction has been accepted into a block; the DAA score depth accumulated
+    /// since acceptance is carried as the inner value.
+    Confirming(u64),
+    /// The transaction has accumulated the requested number of confirmations.
+    Confirmed,
+}
+
 pub(crate) struct PendingTransactionInner {
     /// Generator that produced the transaction
     pub(crate) generator: Generator,
@@ -36,6 +97,14 @@ pub(crate) struct PendingTransactionInner {
     pub(crate) fees: u64,
     /// Whether the transaction is a final or a batch transaction
     pub(crate) is_final: bool,
+    /// Current state of [`PendingTransaction::confirmations`]
+    pub(crate) confirmation_state: Mutex<ConfirmationState>,
+    /// Number of confirmations requested via [`PendingTransaction::confirmations`]
+    pub(crate) confirmation_target: AtomicU64,
+    /// DAA score at which the transaction was first observed as accepted (0 if unknown)
+    pub(crate) accepting_daa_score: AtomicU64,
+    /// Partial signatures collected from co-signers via [`PendingTransaction::merge_signatures`]
+    pub(crate) partial_signatures: Mutex<Vec<PartialSignature>>,
 }
 
 /// Meta transaction encapsulating a transaction generated by the [`Generator`].
@@ -74,6 +143,10 @@ impl PendingTransaction {
                 aggregate_output_value,
                 fees,
                 is_final,
+                confirmation_state: Mutex::new(ConfirmationState::Submitted),
+                confirmation_target: AtomicU64::new(0),
+                accepting_daa_score: AtomicU64::new(0),
+                partial_signatures: Mutex::new(Vec::new()),
             }),
         })
     }
@@ -141,11 +214,74 @@ impl PendingTransaction {
 
     /// Submit the transaction on the supplied rpc
     pub async fn try_submit(&self, rpc: &Arc<DynRpcApi>) -> Result<RpcTransactionId> {
+        self.try_submit_impl(rpc, false).await
+    }
+
+    /// As [`PendingTransaction::try_submit`], but always running [`PendingTransaction::verify`]
+    /// first so callers can trade a little CPU for a guaranteed-valid broadcast.
+    pub async fn try_submit_verified(&self, rpc: &Arc<DynRpcApi>) -> Result<RpcTransactionId> {
+        self.try_submit_impl(rpc, true).await
+    }
+
+    async fn try_submit_impl(&self, rpc: &Arc<DynRpcApi>, verify: bool) -> Result<RpcTransactionId> {
+        if verify {
+            self.verify()?;
+        }
         self.commit().await?; // commit transactions only if we are submitting
         let rpc_transaction: RpcTransaction = self.rpc_transaction();
         Ok(rpc.submit_transaction(rpc_transaction, true).await?)
     }
 
+    /// Locally validate that every input's signature/script solves its corresponding
+    /// [`UtxoEntryReference`] script pubkey and amount, without any RPC round-trip.
+    /// Run before [`PendingTransaction::try_submit`] (via [`PendingTransaction::try_submit_verified`])
+    /// to catch malformed transactions before they waste a round-trip to the node.
+    pub fn verify(&self) -> Result<()> {
+        let signable_tx = self.inner.signable_tx.lock()?.clone();
+        verify_signable_transaction(&signable_tx, &self.inner.utxo_entries)
+    }
+
+    /// Submit the transaction and wait for it to propagate - either into the mempool or,
+    /// if the node was fast enough, straight into an accepting block - periodically
+    /// resubmitting the identical signed transaction (same id, so resubmission is
+    /// idempotent) while neither is observed, until `opts.timeout` elapses. Returns
+    /// [`Error::ConfirmationTimeout`] carrying the transaction id and elapsed time if
+    /// the deadline is reached without the transaction becoming visible.
+    pub async fn try_submit_and_await(&self, rpc: &Arc<DynRpcApi>, opts: SubmitOptions) -> Result<RpcTransactionId> {
+        let started = tokio::time::Instant::now();
+        let deadline = started + opts.timeout;
+        let rpc_transaction = self.rpc_transaction();
+        let txid = self.try_submit(rpc).await?;
+
+        loop {
+            let now = tokio::time::Instant::now();
+            if now >= deadline {
+                return Err(Error::ConfirmationTimeout { id: txid, elapsed: started.elapsed() });
+            }
+
+            tokio::time::sleep(opts.rebroadcast_interval.min(deadline - now)).await;
+
+            match rpc.get_mempool_entry(txid, true, true).await {
+                Ok(_) => return Ok(txid),
+                Err(err) if mempool_entry_not_found(&err) => {
+                    // not in the mempool - it may already be accepted, which is also
+                    // a propagation success, or it may genuinely not have arrived yet
+                    if self.is_accepted(rpc, txid).await? {
+                        return Ok(txid);
+                    }
+                }
+                Err(err) => return Err(err.into()),
+            }
+
+            if tokio::time::Instant::now() >= deadline {
+                return Err(Error::ConfirmationTimeout { id: txid, elapsed: started.elapsed() });
+            }
+
+            // same signed transaction, same id - resubmission is idempotent
+            rpc.submit_transaction(rpc_transaction.clone(), true).await?;
+        }
+    }
+
     pub async fn log(&self) -> Result<()> {
         log_info!("pending transaction: {:?}", self.rpc_transaction());
         Ok(())
@@ -164,4 +300,464 @@ impl PendingTransaction {
         *self.inner.signable_tx.lock().unwrap() = signed_tx;
         Ok(())
     }
+
+    /// Sign every input with each of the supplied private keys individually, producing a
+    /// detached bundle of per-input, per-signer candidate signature scripts without
+    /// mutating the stored [`SignableTransaction`]. Built on [`sign_with_multiple_v2`]
+    /// (the same primitive [`PendingTransaction::try_sign_with_keys`] uses), called once
+    /// per key so each co-signer's contribution stays distinguishable by its pubkey.
+    /// Intended for hand-off signing flows where co-signers hold different inputs' keys
+    /// on separate machines and exchange their bundles via
+    /// [`PendingTransaction::merge_signatures`]; see [`PendingTransaction::finalize`] for
+    /// why this is hand-off, not combined m-of-n, signing.
+    pub fn try_sign_partial(&self, privkeys: Vec<[u8; 32]>) -> Result<PartialSignatures> {
+        let transaction_id = self.inner.signable_tx.lock()?.id();
+        let mut signatures = Vec::new();
+        for privkey in &privkeys {
+            let pubkey = public_key_from_secret(privkey)?;
+            let mutable_tx = self.inner.signable_tx.lock()?.clone();
+            let signed = sign_with_multiple_v2(mutable_tx, vec![*privkey]);
+            for (input_index, input) in signed.tx.inputs.iter().enumerate() {
+                if !input.signature_script.is_empty() {
+                    signatures.push(PartialSignature { input_index, pubkey, signature_script: input.signature_script.clone() });
+                }
+            }
+        }
+        Ok(PartialSignatures { transaction_id, signatures })
+    }
+
+    /// Accumulate partial signature bundles collected from other co-signers into this
+    /// pending transaction's in-memory signature pool, keyed by `(input_index, pubkey)` so
+    /// re-merging the same co-signer's bundle (e.g. after a retried exchange) replaces
+    /// their prior contribution rather than inflating the signer count.
+    pub fn merge_signatures(&self, bundles: Vec<PartialSignatures>) -> Result<()> {
+        let txid = self.id();
+        let mut pool = self.inner.partial_signatures.lock().unwrap();
+        for bundle in bundles {
+            if bundle.transaction_id != txid {
+                return Err(Error::Custom(format!("partial signatures are for transaction {} not {txid}", bundle.transaction_id)));
+            }
+            for signature in bundle.signatures {
+                match pool.iter_mut().find(|existing| existing.input_index == signature.input_index && existing.pubkey == signature.pubkey) {
+                    Some(existing) => *existing = signature,
+                    None => pool.push(signature),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Write each input's collected hand-off signature into the stored
+    /// [`SignableTransaction`] and verify the assembled result. This is deliberately
+    /// narrower than m-of-n multisig: Kaspa has no redeem-script/signature-combination
+    /// primitive to assemble multiple co-signers' independently produced signature
+    /// scripts into one combined script, so every input must have been signed by
+    /// exactly one collected co-signer (one pubkey). Fails if any input has no
+    /// collected signature, has candidate signatures from more than one distinct
+    /// signer (ambiguous - this API cannot combine them), or if the assembled
+    /// transaction fails [`PendingTransaction::verify`].
+    pub fn finalize(&self) -> Result<()> {
+        let mut mutable_tx = self.inner.signable_tx.lock()?.clone();
+        let pool = self.inner.partial_signatures.lock().unwrap();
+
+        for input_index in 0..mutable_tx.tx.inputs.len() {
+            let signers: Vec<_> = pool.iter().filter(|s| s.input_index == input_index).collect();
+            match signers.as_slice() {
+                [] => return Err(Error::Custom(format!("input {input_index} has no collected signature"))),
+                [signature] => {
+                    mutable_tx.tx.inputs[input_index].signature_script = signature.signature_script.clone();
+                }
+                _ => {
+                    let distinct_signers = distinct_signer_count(&pool, input_index);
+                    return Err(Error::Custom(format!(
+                        "input {input_index} has {distinct_signers} candidate signatures from different signers; \
+                         finalize() only supports single-signer hand-off per input, not m-of-n aggregation"
+                    )));
+                }
+            }
+        }
+
+        drop(pool);
+        *self.inner.signable_tx.lock().unwrap() = mutable_tx;
+        self.verify()
+    }
+
+    /// Current [`ConfirmationState`] as last observed by [`PendingTransaction::confirmations`].
+    /// Does not perform any RPC calls.
+    pub fn status(&self) -> ConfirmationState {
+        *self.inner.confirmation_state.lock().unwrap()
+    }
+
+    /// Wait for the transaction to accumulate `confirmations` confirmations, driving
+    /// [`PendingTransaction::status`] through `Submitted -> Mempool -> Confirming(n) -> Confirmed`.
+    /// Confirmation depth is approximated via virtual DAA score progression since the
+    /// transaction was first observed to be accepted. Acceptance itself is verified by
+    /// checking that one of the transaction's own outputs now exists as a UTXO, never
+    /// inferred merely from the absence of a mempool entry (which also happens on
+    /// eviction or a transient RPC failure). Dropping the returned future simply stops
+    /// polling; no RPC subscription is left dangling.
+    ///
+    /// Requires at least one of the transaction's outputs to pay one of
+    /// [`PendingTransaction::addresses`] (true for any change output, and for a payment
+    /// to a wallet-owned address) - see [`PendingTransaction::is_accepted`]. This method
+    /// carries no timeout: unlike [`PendingTransaction::try_submit_and_await`], if that
+    /// precondition doesn't hold (e.g. every output pays an address outside the wallet)
+    /// acceptance can never be observed and this polls forever. Callers without that
+    /// guarantee should wrap the call in their own timeout.
+    pub async fn confirmations(&self, rpc: &Arc<DynRpcApi>, confirmations: u64) -> Result<TransactionId> {
+        let txid = self.id();
+        self.inner.confirmation_target.store(confirmations, Ordering::SeqCst);
+        self.inner.accepting_daa_score.store(0, Ordering::SeqCst);
+        *self.inner.confirmation_state.lock().unwrap() = ConfirmationState::Submitted;
+
+        loop {
+            let in_mempool = match rpc.get_mempool_entry(txid, true, true).await {
+                Ok(_) => true,
+                Err(err) if mempool_entry_not_found(&err) => false,
+                Err(err) => return Err(err.into()),
+            };
+
+            let accepted = !in_mempool && self.is_accepted(rpc, txid).await?;
+            let virtual_daa_score =
+                if accepted { rpc.get_block_dag_info().await?.virtual_daa_score } else { 0 };
+
+            let current = *self.inner.confirmation_state.lock().unwrap();
+            let accepting_daa_score = self.inner.accepting_daa_score.load(Ordering::SeqCst);
+            let (next_state, next_accepting_daa_score) =
+                next_confirmation_state(current, in_mempool, accepted, virtual_daa_score, accepting_daa_score, confirmations);
+
+            *self.inner.confirmation_state.lock().unwrap() = next_state;
+            self.inner.accepting_daa_score.store(next_accepting_daa_score, Ordering::SeqCst);
+
+            if next_state == ConfirmationState::Confirmed {
+                return Ok(txid);
+            }
+
+            tokio::time::sleep(CONFIRMATION_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Best-effort local proof that `txid` has been accepted into a block: true if one of
+    /// this transaction's own outputs now exists as a UTXO at one of its addresses. This
+    /// deliberately does not infer acceptance merely from a missing mempool entry, since
+    /// eviction and transient RPC failures look the same from the caller's side.
+    ///
+    /// Precondition: [`PendingTransaction::addresses`] must cover at least one of this
+    /// transaction's own outputs (its change output, or a payment back to a wallet-owned
+    /// address). If every output pays an address this wallet doesn't track, this always
+    /// returns `false` even after acceptance, with no way to distinguish that from "not
+    /// yet accepted" - see the caveat on [`PendingTransaction::confirmations`].
+    async fn is_accepted(&self, rpc: &Arc<DynRpcApi>, txid: TransactionId) -> Result<bool> {
+        let utxos = rpc.get_utxos_by_addresses(self.addresses().clone()).await?;
+        Ok(utxos.iter().any(|utxo| utxo.outpoint.transaction_id == txid))
+    }
+}
+
+/// Derive the x-only Schnorr public key corresponding to a raw secp256k1 private key,
+/// used to tag each co-signer's contribution in a [`PartialSignature`].
+fn public_key_from_secret(privkey: &[u8; 32]) -> Result<[u8; 32]> {
+    let secret = secp256k1::SecretKey::from_slice(privkey).map_err(|err| Error::Custom(err.to_string()))?;
+    let (pubkey, _parity) = secret.x_only_public_key(secp256k1::SECP256K1);
+    Ok(pubkey.serialize())
+}
+
+/// Number of distinct co-signers (by pubkey) that have contributed a signature for
+/// `input_index`, kept separate from [`PendingTransaction::finalize`] so it can be unit
+/// tested without touching RPC, signing, or the stored transaction.
+fn distinct_signer_count(pool: &[PartialSignature], input_index: usize) -> usize {
+    pool.iter().filter(|s| s.input_index == input_index).map(|s| s.pubkey).collect::<std::collections::BTreeSet<_>>().len()
+}
+
+/// True if `error` reflects "no such mempool entry" rather than some other RPC failure
+/// (e.g. a transient connection error), which callers must not conflate with acceptance.
+fn mempool_entry_not_found(error: &kaspa_rpc_core::RpcError) -> bool {
+    error.to_string().to_lowercase().contains("not found")
+}
+
+/// Run script verification for every input of `signable_tx` against its corresponding
+/// [`UtxoEntryReference`], kept separate from [`PendingTransaction::verify`] so it can be
+/// unit tested with a hand-built transaction instead of a full [`PendingTransaction`].
+fn verify_signable_transaction(signable_tx: &SignableTransaction, utxo_entries: &[UtxoEntryReference]) -> Result<()> {
+    for (input_index, utxo_entry) in utxo_entries.iter().enumerate() {
+        kaspa_txscript::TxScriptEngine::from_transaction_input(signable_tx, input_index, &utxo_entry.utxo.entry)
+            .and_then(|mut engine| engine.execute())
+            .map_err(|source| Error::ScriptVerification { input_index, source })?;
+    }
+    Ok(())
+}
+
+/// Pure state transition for [`PendingTransaction::confirmations`], kept separate from
+/// RPC plumbing so it can be unit tested directly. Returns the next [`ConfirmationState`]
+/// and the (possibly unchanged) accepting DAA score to persist.
+fn next_confirmation_state(
+    current: ConfirmationState,
+    in_mempool: bool,
+    accepted: bool,
+    virtual_daa_score: u64,
+    accepting_daa_score: u64,
+    target: u64,
+) -> (ConfirmationState, u64) {
+    if in_mempool {
+        return (ConfirmationState::Mempool, 0);
+    }
+    if !accepted {
+        // neither in the mempool nor observably accepted yet - stay put rather than
+        // guessing; this covers propagation delay as well as a transient lookup miss
+        return (current, accepting_daa_score);
+    }
+    if accepting_daa_score == 0 {
+        return (ConfirmationState::Confirming(0), virtual_daa_score);
+    }
+    let depth = virtual_daa_score.saturating_sub(accepting_daa_score);
+    if depth >= target {
+        (ConfirmationState::Confirmed, accepting_daa_score)
+    } else {
+        (ConfirmationState::Confirming(depth), accepting_daa_score)
+    }
+}
+
+/// Options controlling [`submit_batch`].
+#[derive(Debug, Clone, Copy)]
+pub struct SubmitBatchOptions {
+    /// Maximum number of transactions submitted to the node per second, within a single
+    /// chain. Chains are independent [`Generator`] runs, so this does not bound the
+    /// node's overall incoming rate when `max_concurrency` > 1.
+    pub max_tps: u32,
+    /// Maximum number of independent chains submitted concurrently.
+    pub max_concurrency: usize,
+}
+
+impl Default for SubmitBatchOptions {
+    fn default() -> Self {
+        Self { max_tps: 20, max_concurrency: 8 }
+    }
+}
+
+/// Live progress of a [`submit_batch`] run: the number of submissions currently
+/// in flight and a rolling transactions-per-second rate since the first submission.
+#[derive(Default)]
+pub struct SubmitBatchMetrics {
+    in_flight: AtomicUsize,
+    submitted: AtomicUsize,
+    started: Mutex<Option<tokio::time::Instant>>,
+}
+
+impl SubmitBatchMetrics {
+    /// Number of submissions currently awaiting a node response.
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Rolling transactions-per-second rate since the first submission.
+    pub fn tps(&self) -> f64 {
+        let submitted = self.submitted.load(Ordering::SeqCst) as f64;
+        match *self.started.lock().unwrap() {
+            Some(started) => submitted / started.elapsed().as_secs_f64().max(0.001),
+            None => 0.0,
+        }
+    }
+}
+
+/// Submit `chains` - each an independent [`Generator`] run's output - so that wallets
+/// moving large UTXO sets don't overwhelm a node. A single [`Generator`] run forms a
+/// dependency chain: each batch transaction spends the previous batch transaction's
+/// change output, and the final transaction spends the last batch transaction's change
+/// output. Within a chain, transactions are therefore submitted strictly in order, the
+/// node's reply to each submission awaited before the next is submitted (block acceptance
+/// is not waited for), and the final transaction(s)
+/// are only submitted if every batch transaction they depend on actually succeeded.
+/// Chains carry no dependency on one another, so up to `options.max_concurrency` of them
+/// are submitted concurrently, saturating the node instead of serializing unrelated work
+/// behind a single chain's pace. Returns a map from transaction id to the per-transaction
+/// submission result; a transaction skipped because an earlier dependency in its own
+/// chain failed is recorded as an `Err`.
+pub async fn submit_batch(
+    rpc: &Arc<DynRpcApi>,
+    chains: impl IntoIterator<Item = impl IntoIterator<Item = PendingTransaction>>,
+    options: SubmitBatchOptions,
+) -> Result<(HashMap<TransactionId, Result<RpcTransactionId>>, Arc<SubmitBatchMetrics>)> {
+    let metrics = Arc::new(SubmitBatchMetrics::default());
+
+    let per_chain_results = stream::iter(chains.into_iter().map(|chain| {
+        let rpc = rpc.clone();
+        let options = options;
+        let metrics = metrics.clone();
+        async move { submit_one_chain(&rpc, chain.into_iter().collect(), &options, &metrics).await }
+    }))
+    .buffer_unordered(options.max_concurrency.max(1))
+    .collect::<Vec<_>>()
+    .await;
+
+    let mut results = HashMap::new();
+    for chain_results in per_chain_results {
+        results.extend(chain_results);
+    }
+
+    Ok((results, metrics))
+}
+
+/// Submit a single chain's batch transactions in order, then its final transaction(s)
+/// only if every batch transaction succeeded.
+async fn submit_one_chain(
+    rpc: &Arc<DynRpcApi>,
+    transactions: Vec<PendingTransaction>,
+    options: &SubmitBatchOptions,
+    metrics: &Arc<SubmitBatchMetrics>,
+) -> HashMap<TransactionId, Result<RpcTransactionId>> {
+    let (batch, finals): (Vec<_>, Vec<_>) = transactions.into_iter().partition(|tx| tx.is_batch());
+
+    let mut results = submit_chain(rpc, batch, options, metrics).await;
+
+    if chain_succeeded(&results) {
+        results.extend(submit_chain(rpc, finals, options, metrics).await);
+    } else {
+        for pending in &finals {
+            results.insert(
+                pending.id(),
+                Err(Error::Custom("skipped: a funding batch transaction in this chain failed".to_string())),
+            );
+        }
+    }
+
+    results
+}
+
+/// True if every transaction submitted so far in the chain succeeded.
+fn chain_succeeded(results: &HashMap<TransactionId, Result<RpcTransactionId>>) -> bool {
+    results.values().all(|result| result.is_ok())
+}
+
+/// Submit `transactions` one at a time, in the given (dependency) order, awaiting the
+/// node's reply to each submission (not block acceptance) before submitting the next,
+/// and rate-limited to at most
+/// `options.max_tps` submissions per second. Stops submitting, and records the remaining
+/// transactions as skipped, as soon as one submission fails.
+async fn submit_chain(
+    rpc: &Arc<DynRpcApi>,
+    transactions: Vec<PendingTransaction>,
+    options: &SubmitBatchOptions,
+    metrics: &Arc<SubmitBatchMetrics>,
+) -> HashMap<TransactionId, Result<RpcTransactionId>> {
+    let min_interval = Duration::from_secs_f64(1.0 / options.max_tps.max(1) as f64);
+
+    let mut results = HashMap::with_capacity(transactions.len());
+    let mut chain_broken = false;
+
+    for pending in transactions {
+        let id = pending.id();
+        if chain_broken {
+            results.insert(id, Err(Error::Custom("skipped: an earlier transaction in this chain failed".to_string())));
+            continue;
+        }
+
+        metrics.started.lock().unwrap().get_or_insert_with(tokio::time::Instant::now);
+        tokio::time::sleep(min_interval).await;
+
+        metrics.in_flight.fetch_add(1, Ordering::SeqCst);
+        let result = pending.try_submit(rpc).await;
+        metrics.in_flight.fetch_sub(1, Ordering::SeqCst);
+        metrics.submitted.fetch_add(1, Ordering::SeqCst);
+
+        chain_broken = result.is_err();
+        results.insert(id, result);
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn confirmation_state_mempool_takes_priority() {
+        let (state, daa) = next_confirmation_state(ConfirmationState::Confirming(3), true, false, 100, 50, 10);
+        assert_eq!(state, ConfirmationState::Mempool);
+        assert_eq!(daa, 0);
+    }
+
+    #[test]
+    fn confirmation_state_stays_put_when_neither_mempool_nor_accepted() {
+        let (state, daa) = next_confirmation_state(ConfirmationState::Submitted, false, false, 100, 0, 10);
+        assert_eq!(state, ConfirmationState::Submitted);
+        assert_eq!(daa, 0);
+    }
+
+    #[test]
+    fn confirmation_state_records_baseline_on_first_acceptance() {
+        let (state, daa) = next_confirmation_state(ConfirmationState::Mempool, false, true, 500, 0, 10);
+        assert_eq!(state, ConfirmationState::Confirming(0));
+        assert_eq!(daa, 500);
+    }
+
+    #[test]
+    fn confirmation_state_progresses_with_daa_score() {
+        let (state, daa) = next_confirmation_state(ConfirmationState::Confirming(0), false, true, 505, 500, 10);
+        assert_eq!(state, ConfirmationState::Confirming(5));
+        assert_eq!(daa, 500);
+    }
+
+    #[test]
+    fn confirmation_state_confirms_once_target_reached() {
+        let (state, _) = next_confirmation_state(ConfirmationState::Confirming(9), false, true, 510, 500, 10);
+        assert_eq!(state, ConfirmationState::Confirmed);
+    }
+
+    #[test]
+    fn chain_succeeded_true_when_all_results_ok() {
+        let mut results: HashMap<TransactionId, Result<RpcTransactionId>> = HashMap::new();
+        results.insert(TransactionId::default(), Ok(RpcTransactionId::default()));
+        assert!(chain_succeeded(&results));
+    }
+
+    #[test]
+    fn chain_succeeded_false_when_any_result_failed() {
+        let mut results: HashMap<TransactionId, Result<RpcTransactionId>> = HashMap::new();
+        results.insert(TransactionId::default(), Ok(RpcTransactionId::default()));
+        results.insert(TransactionId::from([1u8; 32]), Err(Error::Custom("submission rejected".to_string())));
+        assert!(!chain_succeeded(&results));
+    }
+
+    fn partial_signature(input_index: usize, pubkey: u8) -> PartialSignature {
+        PartialSignature { input_index, pubkey: [pubkey; 32], signature_script: vec![pubkey] }
+    }
+
+    #[test]
+    fn distinct_signer_count_ignores_other_inputs() {
+        let pool = vec![partial_signature(0, 1), partial_signature(1, 2)];
+        assert_eq!(distinct_signer_count(&pool, 0), 1);
+    }
+
+    #[test]
+    fn distinct_signer_count_dedups_same_pubkey() {
+        let pool = vec![partial_signature(0, 1), partial_signature(0, 1), partial_signature(0, 2)];
+        assert_eq!(distinct_signer_count(&pool, 0), 2);
+    }
+
+    #[test]
+    fn verify_signable_transaction_rejects_unsigned_input() {
+        use kaspa_addresses::{Prefix, Version};
+        use kaspa_consensus_core::subnets::SUBNETWORK_ID_NATIVE;
+        use kaspa_consensus_core::tx::{TransactionInput, TransactionOutpoint, TransactionOutput, UtxoEntry};
+        use kaspa_txscript::pay_to_address_script;
+
+        // A P2PK locking script requires a valid Schnorr signature in the input's
+        // signature_script; leaving it empty must make script execution fail.
+        let address = Address::new(Prefix::Mainnet, Version::PubKey, &[7u8; 32]);
+        let script_public_key = pay_to_address_script(&address);
+        let entry = UtxoEntry::new(1_000, script_public_key.clone(), 0, false);
+
+        let previous_outpoint = TransactionOutpoint::new(TransactionId::default(), 0);
+        let input = TransactionInput::new(previous_outpoint, vec![], 0, 1);
+        let output = TransactionOutput::new(900, script_public_key);
+        let tx = Transaction::new(0, vec![input], vec![output], 0, SUBNETWORK_ID_NATIVE, 0, vec![]);
+        let signable_tx = SignableTransaction::with_entries(tx, vec![entry.clone()]);
+
+        let utxo_entries = vec![UtxoEntryReference {
+            utxo: Arc::new(crate::utxo::UtxoEntryReferenceInner { address: None, outpoint: previous_outpoint, entry }),
+        }];
+
+        let result = verify_signable_transaction(&signable_tx, &utxo_entries);
+        assert!(matches!(result, Err(Error::ScriptVerification { input_index: 0, .. })));
+    }
 }
\ No newline at end of file